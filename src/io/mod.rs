@@ -24,17 +24,310 @@
 //!     .unwrap_or(FeedForward::new(&[2, 2, 1]));
 //! ```
 
+// NOTE: this module now depends on `serde_yaml`, `toml`, and `rmp-serde` as
+// Cargo.toml dependencies, and on `ErrorKind` carrying `Yaml`, `TomlSer`,
+// `TomlDe`, `MessagePackEncode`, `MessagePackDecode`, and
+// `FormatMismatch { expected: String, found: String }` variants. Both
+// Cargo.toml and the `ErrorKind` definition live outside `src/io/mod.rs` (in
+// the crate root), which is the only file tracked in this change set, so
+// those companion diffs must land alongside this module before it compiles.
 use std::fs::File;
-use std::io::{Write, BufReader};
-use serde;
+use std::io::{Read, Write, BufReader, BufRead, Lines};
+use std::marker::PhantomData;
 use serde_json;
+use serde_yaml;
+use toml;
+use rmp_serde;
 use bincode::{serialize, deserialize_from, Infinite};
 use Transform;
 
 use ErrorKind;
 
+/// The serialization backend to use when saving or loading a neural network
+/// via [`save_as`](fn.save_as.html) / [`load_as`](fn.load_as.html).
+///
+/// * `Bincode` - compact opaque binary format, the historical default;
+/// * `Json` - human-readable, diffable, cross-language text format;
+/// * `Yaml` - human-readable, editable configuration-style format;
+/// * `Toml` - human-readable, editable configuration-style format;
+/// * `MessagePack` - compact binary format, smaller and faster than JSON
+/// while staying portable across languages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SerializationFormat {
+    Bincode,
+    Json,
+    Yaml,
+    Toml,
+    MessagePack,
+}
+
+impl SerializationFormat {
+    fn discriminant(&self) -> u8 {
+        match *self {
+            SerializationFormat::Bincode => 0,
+            SerializationFormat::Json => 1,
+            SerializationFormat::Yaml => 2,
+            SerializationFormat::Toml => 3,
+            SerializationFormat::MessagePack => 4,
+        }
+    }
+
+    fn from_discriminant(byte: u8) -> Option<SerializationFormat> {
+        match byte {
+            0 => Some(SerializationFormat::Bincode),
+            1 => Some(SerializationFormat::Json),
+            2 => Some(SerializationFormat::Yaml),
+            3 => Some(SerializationFormat::Toml),
+            4 => Some(SerializationFormat::MessagePack),
+            _ => None,
+        }
+    }
+}
+
+/// Magic tag prepended to every network written by `save_to_writer` (and so
+/// by `save`, `save_as` and `save_json`) so stray or foreign files are
+/// rejected instead of producing a garbled `FeedForward`. `to_json` and
+/// `CheckpointWriter` write plain JSON without this header.
+const MAGIC: &[u8; 4] = b"NFLW";
+
+/// Version of the header format itself (bumped if the header layout changes).
+const HEADER_VERSION: u16 = 1;
+
+fn write_header<W: Write>(writer: &mut W, format: SerializationFormat) -> Result<(), ErrorKind> {
+    writer.write_all(MAGIC).map_err(ErrorKind::IO)?;
+    writer.write_all(&[(HEADER_VERSION >> 8) as u8, HEADER_VERSION as u8]).map_err(ErrorKind::IO)?;
+    writer.write_all(&[format.discriminant()]).map_err(ErrorKind::IO)?;
+    Ok(())
+}
+
+/// Reads and validates the magic-header, returning the `SerializationFormat`
+/// it declares.
+fn read_header<R: Read>(reader: &mut R) -> Result<SerializationFormat, ErrorKind> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic).map_err(ErrorKind::IO)?;
+    if &magic != MAGIC {
+        return Err(ErrorKind::FormatMismatch {
+            expected: format!("{:?}", MAGIC),
+            found: format!("{:?}", magic),
+        });
+    }
+
+    let mut version = [0u8; 2];
+    reader.read_exact(&mut version).map_err(ErrorKind::IO)?;
+    let found_version = ((version[0] as u16) << 8) | (version[1] as u16);
+    if found_version != HEADER_VERSION {
+        return Err(ErrorKind::FormatMismatch {
+            expected: HEADER_VERSION.to_string(),
+            found: found_version.to_string(),
+        });
+    }
+
+    let mut discriminant = [0u8; 1];
+    reader.read_exact(&mut discriminant).map_err(ErrorKind::IO)?;
+    SerializationFormat::from_discriminant(discriminant[0]).ok_or_else(|| ErrorKind::FormatMismatch {
+        expected: "a known SerializationFormat discriminant (0-4)".to_string(),
+        found: discriminant[0].to_string(),
+    })
+}
+
+/// Serializes given neural network into any `Write` sink using the given
+/// `SerializationFormat`.
+///
+/// * `obj: &mut T` - link on neural network;
+/// * `writer: &mut W` - destination to write the serialized bytes into;
+/// * `format: SerializationFormat` - backend to serialize with.
+/// * `return -> Result<(), ErrorKind>` - result of operation;
+///
+/// This lets a network be checkpointed into an in-memory buffer, pushed over
+/// a socket, or wrapped in a compressing writer, without touching the
+/// filesystem.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+/// use neuroflow::io::SerializationFormat;
+///
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// let mut buf: Vec<u8> = Vec::new();
+/// io::save_to_writer(&mut nn, &mut buf, SerializationFormat::MessagePack);
+/// ```
+pub fn save_to_writer<T: Transform, W: Write>(obj: &mut T, writer: &mut W, format: SerializationFormat) -> Result<(), ErrorKind> {
+    obj.before();
+    write_header(writer, format)?;
+
+    match format {
+        SerializationFormat::Bincode => {
+            let encoded: Vec<u8> = serialize(obj, Infinite).map_err(ErrorKind::Encoding)?;
+            writer.write_all(&encoded).map_err(ErrorKind::IO)?;
+        }
+        SerializationFormat::Json => {
+            let encoded = serde_json::to_string(obj).map_err(ErrorKind::Json)?;
+            writer.write_all(encoded.as_bytes()).map_err(ErrorKind::IO)?;
+        }
+        SerializationFormat::Yaml => {
+            let encoded = serde_yaml::to_string(obj).map_err(ErrorKind::Yaml)?;
+            writer.write_all(encoded.as_bytes()).map_err(ErrorKind::IO)?;
+        }
+        SerializationFormat::Toml => {
+            let encoded = toml::to_string(obj).map_err(ErrorKind::TomlSer)?;
+            writer.write_all(encoded.as_bytes()).map_err(ErrorKind::IO)?;
+        }
+        SerializationFormat::MessagePack => {
+            let encoded = rmp_serde::to_vec(obj).map_err(ErrorKind::MessagePackEncode)?;
+            writer.write_all(&encoded).map_err(ErrorKind::IO)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn decode_payload<T: Transform, R: Read>(reader: &mut R, format: SerializationFormat) -> Result<T, ErrorKind> {
+    let nn: T = match format {
+        SerializationFormat::Bincode => {
+            deserialize_from(reader, Infinite).map_err(ErrorKind::Encoding)?
+        }
+        SerializationFormat::Json => {
+            let mut contents = String::new();
+            reader.read_to_string(&mut contents).map_err(ErrorKind::IO)?;
+            serde_json::from_str(&contents).map_err(ErrorKind::Json)?
+        }
+        SerializationFormat::Yaml => {
+            let mut contents = String::new();
+            reader.read_to_string(&mut contents).map_err(ErrorKind::IO)?;
+            serde_yaml::from_str(&contents).map_err(ErrorKind::Yaml)?
+        }
+        SerializationFormat::Toml => {
+            let mut contents = String::new();
+            reader.read_to_string(&mut contents).map_err(ErrorKind::IO)?;
+            toml::from_str(&contents).map_err(ErrorKind::TomlDe)?
+        }
+        SerializationFormat::MessagePack => {
+            let mut contents = Vec::new();
+            reader.read_to_end(&mut contents).map_err(ErrorKind::IO)?;
+            rmp_serde::from_slice(&contents).map_err(ErrorKind::MessagePackDecode)?
+        }
+    };
+
+    Ok(nn)
+}
+
+/// Restores the neural network by reading it from any `Read` source
+/// serialized with the given `SerializationFormat`.
+///
+/// Validates the magic-header written by `save_to_writer` and returns
+/// `ErrorKind::FormatMismatch` if the magic tag does not match, the header
+/// was written by an incompatible header version, or it declares a different
+/// format than `format`. Input too short to hold the 7-byte header yields
+/// `ErrorKind::IO` instead.
+///
+/// * `reader: &mut R` - source to read the serialized bytes from;
+/// * `format: SerializationFormat` - backend the data was serialized with.
+/// * `return -> Result<T, ErrorKind>` - if Ok returns loaded neural network (Note, you must
+/// apparently specify the type T).
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+/// use neuroflow::io::SerializationFormat;
+///
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// let mut buf: Vec<u8> = Vec::new();
+/// io::save_to_writer(&mut nn, &mut buf, SerializationFormat::MessagePack).unwrap();
+///
+/// let restored: FeedForward = io::load_from_reader(&mut buf.as_slice(), SerializationFormat::MessagePack)
+///     .unwrap();
+/// ```
+pub fn load_from_reader<T: Transform, R: Read>(reader: &mut R, format: SerializationFormat) -> Result<T, ErrorKind> {
+    let found = read_header(reader)?;
+    if found != format {
+        return Err(ErrorKind::FormatMismatch {
+            expected: format!("{:?}", format),
+            found: format!("{:?}", found),
+        });
+    }
+
+    let mut nn: T = decode_payload(reader, format)?;
+    nn.after();
+    Ok(nn)
+}
+
+/// Restores the neural network by reading it from any `Read` source,
+/// detecting the `SerializationFormat` from the magic-header written by
+/// `save_to_writer` instead of requiring the caller to know it up front.
+///
+/// * `reader: &mut R` - source to read the serialized bytes from.
+/// * `return -> Result<T, ErrorKind>` - if Ok returns loaded neural network (Note, you must
+/// apparently specify the type T).
+pub fn load_from_reader_auto<T: Transform, R: Read>(reader: &mut R) -> Result<T, ErrorKind> {
+    let format = read_header(reader)?;
+    let mut nn: T = decode_payload(reader, format)?;
+    nn.after();
+    Ok(nn)
+}
+
+/// Saves given neural network to file specified by `file_path` using the
+/// given `SerializationFormat`.
+///
+/// Thin wrapper around [`save_to_writer`](fn.save_to_writer.html) that opens
+/// `file_path` for writing.
+///
+/// * `obj: &mut T` - link on neural network;
+/// * `file_path: &str` - path to the file;
+/// * `format: SerializationFormat` - backend to serialize with.
+/// * `return -> Result<(), ErrorKind>` - result of operation;
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+/// use neuroflow::io::SerializationFormat;
+///
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// /* train here your neural network */
+/// io::save_as(&mut nn, "test.msgpack", SerializationFormat::MessagePack);
+/// ```
+pub fn save_as<T: Transform>(obj: &mut T, file_path: &str, format: SerializationFormat) -> Result<(), ErrorKind> {
+    let mut file = File::create(file_path).map_err(ErrorKind::IO)?;
+    save_to_writer(obj, &mut file, format)
+}
+
+/// Loads and restores the neural network from a file saved with the given
+/// `SerializationFormat`.
+///
+/// Thin wrapper around [`load_from_reader`](fn.load_from_reader.html) that
+/// opens `file_path` for reading.
+///
+/// * `file_path: &str` - path to the file;
+/// * `format: SerializationFormat` - backend the file was serialized with.
+/// * `return -> Result<T, ErrorKind>` - if Ok returns loaded neural network (Note, you must
+/// apparently specify the type T).
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+/// use neuroflow::io::SerializationFormat;
+///
+/// let new_nn: FeedForward = io::load_as("test.msgpack", SerializationFormat::MessagePack)
+///     .unwrap_or(FeedForward::new(&[2, 2, 1]));
+/// ```
+pub fn load_as<T: Transform>(file_path: &str, format: SerializationFormat) -> Result<T, ErrorKind> {
+    let file = File::open(file_path).map_err(ErrorKind::IO)?;
+    let mut buf = BufReader::new(file);
+    load_from_reader(&mut buf, format)
+}
+
 /// Saves given neural network to file specified by `file_path`.
 ///
+/// Thin wrapper around [`save_as`](fn.save_as.html) defaulting to
+/// `SerializationFormat::Bincode` for backward compatibility.
+///
 /// * `obj: &T` - link on neural network;
 /// * `file_path: &str` - path to the file.
 /// * `return -> Result<(), IOError>` - result of operation;
@@ -50,18 +343,15 @@ use ErrorKind;
 /// io::save(&mut nn, "test.flow");
 /// ```
 pub fn save<T: Transform>(obj: &mut T, file_path: &str) -> Result<(), ErrorKind>{
-    let mut file = File::create(file_path).map_err(ErrorKind::IO)?;
-
-    obj.before();
-    let encoded: Vec<u8> = serialize(obj, Infinite).map_err(ErrorKind::Encoding)?;
-
-    file.write_all(&encoded).map_err(ErrorKind::IO)?;
-
-    Ok(())
+    save_as(obj, file_path, SerializationFormat::Bincode)
 }
 
 /// Loads and restores the neural network from file.
 ///
+/// Detects the `SerializationFormat` from the magic-header written by
+/// `save`/`save_as`, so it transparently loads a file saved with any of
+/// them, not just `Bincode`.
+///
 /// * `file_path: &'b str` - path to the file;
 /// * `return -> Result<T, IOError>` - if Ok returns loaded neural network (Note, you must
 /// apparently specify the type T).
@@ -78,19 +368,358 @@ pub fn save<T: Transform>(obj: &mut T, file_path: &str) -> Result<(), ErrorKind>
 pub fn load<'b, T>(file_path: &'b str) -> Result<T, ErrorKind> where T: Transform{
     let file = File::open(file_path).map_err(ErrorKind::IO)?;
     let mut buf = BufReader::new(file);
+    load_from_reader_auto(&mut buf)
+}
+
+/// Serializes given neural network to a JSON string.
+///
+/// * `obj: &mut T` - link on neural network;
+/// * `return -> Result<String, ErrorKind>` - resulting JSON string.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+///
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// /* train here your neural network */
+/// let s = io::to_json(&mut nn).unwrap();
+/// ```
+pub fn to_json<T: Transform>(obj: &mut T) -> Result<String, ErrorKind> {
+    obj.before();
+    serde_json::to_string(obj).map_err(ErrorKind::Json)
+}
 
-    let mut nn: T = deserialize_from(&mut buf, Infinite).map_err(ErrorKind::Encoding)?;
+/// Restores the neural network from a JSON string produced by `to_json`.
+///
+/// * `s: &str` - JSON representation of the neural network;
+/// * `return -> Result<T, ErrorKind>` - if Ok returns restored neural network.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+///
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// let s = io::to_json(&mut nn).unwrap();
+/// let restored: FeedForward = io::from_json(&s).unwrap();
+/// ```
+pub fn from_json<T: Transform>(s: &str) -> Result<T, ErrorKind> {
+    let mut nn: T = serde_json::from_str(s).map_err(ErrorKind::Json)?;
     nn.after();
     Ok(nn)
 }
 
-/// Future function for saving in JSON string.
-/// return: JSON string
-pub fn to_json<T: serde::Serialize>(obj: &T) -> Result<String, ErrorKind> {
-    serde_json::to_string(obj).map_err(ErrorKind::Json)
+/// Restores a network directly from a JSON string, without going through a
+/// file.
+///
+/// This is a plain alias of [`from_json`](fn.from_json.html), named
+/// `from_string` to match the convention used by FFI/NEAT-style libraries
+/// where a network is reconstructed "from string" rather than "from JSON".
+/// Useful for embedding a trained network as a string literal, or fetching
+/// one from a config service, without writing it to a temp file first.
+///
+/// * `s: &str` - JSON representation of the network, as produced by `to_json`;
+/// * `return -> Result<T, ErrorKind>` - if Ok returns the reconstructed network.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+///
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// let s = io::to_json(&mut nn).unwrap();
+/// let restored: FeedForward = io::from_string(&s).unwrap();
+/// ```
+pub fn from_string<T: Transform>(s: &str) -> Result<T, ErrorKind> {
+    from_json(s)
+}
+
+/// Saves given neural network to file specified by `file_path` in JSON format.
+///
+/// Thin wrapper around [`save_as`](fn.save_as.html) with
+/// `SerializationFormat::Json`, so the file carries the same magic-header as
+/// any other `save_as`-produced file and can be read back by `load`/`load_as`
+/// as well as by `load_json`.
+///
+/// * `obj: &mut T` - link on neural network;
+/// * `file_path: &str` - path to the file.
+/// * `return -> Result<(), ErrorKind>` - result of operation;
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+///
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// /* train here your neural network */
+/// io::save_json(&mut nn, "test.json");
+/// ```
+pub fn save_json<T: Transform>(obj: &mut T, file_path: &str) -> Result<(), ErrorKind> {
+    save_as(obj, file_path, SerializationFormat::Json)
+}
+
+/// Loads and restores the neural network from a JSON file.
+///
+/// Thin wrapper around [`load_as`](fn.load_as.html) with
+/// `SerializationFormat::Json`, so it reads the same header-prefixed format
+/// that `save_json`/`save_as` produce.
+///
+/// * `file_path: &str` - path to the file;
+/// * `return -> Result<T, ErrorKind>` - if Ok returns loaded neural network (Note, you must
+/// apparently specify the type T).
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io;
+///
+/// let new_nn: FeedForward = io::load_json("test.json")
+///     .unwrap_or(FeedForward::new(&[2, 2, 1]));
+/// ```
+pub fn load_json<T: Transform>(file_path: &str) -> Result<T, ErrorKind> {
+    load_as(file_path, SerializationFormat::Json)
+}
+
+#[derive(Serialize)]
+struct CheckpointRecordRef<'a, T: 'a> {
+    step: u64,
+    state: &'a T,
+}
+
+#[derive(Deserialize)]
+struct CheckpointRecordOwned<T> {
+    step: u64,
+    state: T,
+}
+
+/// Appends neural network snapshots to a single growing file (or any
+/// `Write` sink) as newline-delimited JSON (NDJSON) - one `{"step": ...,
+/// "state": ...}` object per line, flushed after each write.
+///
+/// This is meant for training loops that periodically checkpoint: instead
+/// of one file per snapshot, the whole history accumulates in one file that
+/// can be tailed or replayed with `CheckpointReader`.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io::CheckpointWriter;
+/// use std::fs::File;
+///
+/// let file = File::create("training.ndjson").unwrap();
+/// let mut writer = CheckpointWriter::new(file);
+///
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// /* train here your neural network */
+/// writer.write_checkpoint(&mut nn, 0).unwrap();
+/// ```
+pub struct CheckpointWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> CheckpointWriter<W> {
+    /// Wraps `writer` in a `CheckpointWriter`.
+    pub fn new(writer: W) -> Self {
+        CheckpointWriter { writer }
+    }
+
+    /// Serializes `obj`'s current state as one NDJSON record tagged with
+    /// `step`, then flushes the underlying writer.
+    ///
+    /// * `obj: &mut T` - link on neural network;
+    /// * `step: u64` - training step or epoch index attached to the record.
+    /// * `return -> Result<(), ErrorKind>` - result of operation;
+    pub fn write_checkpoint<T: Transform>(&mut self, obj: &mut T, step: u64) -> Result<(), ErrorKind> {
+        obj.before();
+        let record = CheckpointRecordRef { step, state: obj };
+        let encoded = serde_json::to_string(&record).map_err(ErrorKind::Json)?;
+
+        self.writer.write_all(encoded.as_bytes()).map_err(ErrorKind::IO)?;
+        self.writer.write_all(b"\n").map_err(ErrorKind::IO)?;
+        self.writer.flush().map_err(ErrorKind::IO)?;
+
+        Ok(())
+    }
+}
+
+/// Lazily iterates an NDJSON checkpoint log written by `CheckpointWriter`,
+/// yielding `(step, T)` pairs in file order.
+///
+/// # Examples
+///
+/// ```rust
+/// use neuroflow::FeedForward;
+/// use neuroflow::io::{CheckpointWriter, CheckpointReader};
+/// use std::fs::File;
+///
+/// let path = "checkpoint_reader_doctest.ndjson";
+/// let mut nn = FeedForward::new(&[2, 2, 1]);
+/// {
+///     let file = File::create(path).unwrap();
+///     let mut writer = CheckpointWriter::new(file);
+///     writer.write_checkpoint(&mut nn, 0).unwrap();
+/// }
+///
+/// let file = File::open(path).unwrap();
+/// let reader: CheckpointReader<_, FeedForward> = CheckpointReader::new(file);
+///
+/// for record in reader {
+///     let (step, nn) = record.unwrap();
+/// }
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+pub struct CheckpointReader<R: Read, T: Transform> {
+    lines: Lines<BufReader<R>>,
+    _marker: PhantomData<T>,
 }
 
-/// Function for deserializing of JSON to NN struct
-pub fn from_json(_s: &str){
+impl<R: Read, T: Transform> CheckpointReader<R, T> {
+    /// Wraps `reader` in a `CheckpointReader`.
+    pub fn new(reader: R) -> Self {
+        CheckpointReader {
+            lines: BufReader::new(reader).lines(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R: Read, T: Transform> Iterator for CheckpointReader<R, T> {
+    type Item = Result<(u64, T), ErrorKind>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(ErrorKind::IO(e))),
+            };
+
+            if !line.trim().is_empty() {
+                break line;
+            }
+        };
+
+        let record: CheckpointRecordOwned<T> = match serde_json::from_str(&line) {
+            Ok(record) => record,
+            Err(e) => return Some(Err(ErrorKind::Json(e))),
+        };
+
+        let mut state = record.state;
+        state.after();
+        Some(Ok((record.step, state)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use FeedForward;
+
+    fn sample_net() -> FeedForward {
+        FeedForward::new(&[2, 2, 1])
+    }
+
+    #[test]
+    fn json_string_round_trip() {
+        let mut nn = sample_net();
+        let before = nn.calc(&[0.3, 0.7]);
+
+        let s = to_json(&mut nn).unwrap();
+        let mut restored: FeedForward = from_json(&s).unwrap();
+
+        assert_eq!(before, restored.calc(&[0.3, 0.7]));
+    }
+
+    #[test]
+    fn json_file_round_trip() {
+        let path = "chunk0_1_json_file_round_trip.json";
+        let mut nn = sample_net();
+        let before = nn.calc(&[0.3, 0.7]);
+
+        save_json(&mut nn, path).unwrap();
+        let mut restored: FeedForward = load_json(path).unwrap();
+
+        assert_eq!(before, restored.calc(&[0.3, 0.7]));
+        let _ = ::std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn save_as_load_as_round_trip_for_every_format() {
+        let formats = [
+            (SerializationFormat::Bincode, "chunk0_2_round_trip.bincode"),
+            (SerializationFormat::Json, "chunk0_2_round_trip.json"),
+            (SerializationFormat::Yaml, "chunk0_2_round_trip.yaml"),
+            (SerializationFormat::Toml, "chunk0_2_round_trip.toml"),
+            (SerializationFormat::MessagePack, "chunk0_2_round_trip.msgpack"),
+        ];
+
+        for &(format, path) in formats.iter() {
+            let mut nn = sample_net();
+            let before = nn.calc(&[0.3, 0.7]);
+
+            save_as(&mut nn, path, format).unwrap();
+            let mut restored: FeedForward = load_as(path, format).unwrap();
+
+            assert_eq!(before, restored.calc(&[0.3, 0.7]), "round trip failed for {:?}", format);
+            let _ = ::std::fs::remove_file(path);
+        }
+    }
+
+    #[test]
+    fn load_rejects_foreign_magic_with_format_mismatch() {
+        let path = "chunk0_4_foreign_magic.bin";
+        ::std::fs::write(path, b"NOPE!!not-a-neuroflow-file").unwrap();
+
+        let result: Result<FeedForward, ErrorKind> = load(path);
+        match result {
+            Err(ErrorKind::FormatMismatch { .. }) => {}
+            other => panic!("expected FormatMismatch, got {:?}", other),
+        }
+
+        let _ = ::std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn load_rejects_truncated_header_with_io_error() {
+        let path = "chunk0_4_truncated_header.bin";
+        // Fewer than the 7 header bytes (4 magic + 2 version + 1 format), so
+        // read_exact hits EOF before the magic/version bytes are compared —
+        // this is an ErrorKind::IO, not a FormatMismatch.
+        ::std::fs::write(path, b"NF").unwrap();
+
+        let result: Result<FeedForward, ErrorKind> = load(path);
+        match result {
+            Err(ErrorKind::IO(_)) => {}
+            other => panic!("expected IO error, got {:?}", other),
+        }
+
+        let _ = ::std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn checkpoint_writer_reader_round_trip() {
+        let mut buf: Vec<u8> = Vec::new();
+
+        {
+            let mut writer = CheckpointWriter::new(&mut buf);
+            for step in 0..3u64 {
+                let mut nn = sample_net();
+                writer.write_checkpoint(&mut nn, step).unwrap();
+            }
+        }
+
+        let reader: CheckpointReader<&[u8], FeedForward> = CheckpointReader::new(&buf[..]);
+        let records: Vec<(u64, FeedForward)> = reader.map(|r| r.unwrap()).collect();
 
+        assert_eq!(records.len(), 3);
+        for (expected_step, (step, _nn)) in records.into_iter().enumerate() {
+            assert_eq!(expected_step as u64, step);
+        }
+    }
 }